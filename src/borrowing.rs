@@ -0,0 +1,36 @@
+// Ownership and borrowing demos that build on `str_2`'s `&mut str1`.
+
+pub fn move_semantics() {
+    let str1 = String::from("Hello, Move");
+    let str2 = str1;
+    // println!("{}", str1);
+    println!("move: {}", str2);
+}
+
+pub fn shared_references() {
+    let str1 = String::from("Hello, Shared");
+    let ref1 = &str1;
+    let ref2 = &str1;
+    println!("shared: {} {} {}", str1, ref1, ref2);
+}
+
+pub fn mutable_reference() {
+    let mut str1 = String::from("Hello, Mutable");
+    let str2 = &mut str1;
+    // let str3 = &mut str1;
+    str2.push_str(", Rust");
+    println!("mutable: {}", str2);
+}
+
+pub fn first_word(s: &str) -> &str {
+    match s.find(' ') {
+        Some(i) => &s[..i],
+        None => s,
+    }
+}
+
+pub fn borrowed_slice() {
+    let sentence = String::from("Hello, Borrow");
+    let word = first_word(&sentence);
+    println!("first_word: {}", word);
+}
@@ -0,0 +1,68 @@
+// Thread-based and async demos, mirroring the producer loop in `my_for_loop_1`.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+use std::thread;
+
+pub fn threaded_sum() {
+    let total = Arc::new(Mutex::new(0));
+    let (tx, rx) = mpsc::channel();
+
+    let producer = thread::spawn(move || {
+        for n in 1..=3 {
+            tx.send(n).unwrap();
+        }
+    });
+
+    let total_clone = Arc::clone(&total);
+    let consumer = thread::spawn(move || {
+        for n in rx {
+            let mut guard = total_clone.lock().unwrap();
+            *guard += n;
+        }
+    });
+
+    producer.join().unwrap();
+    consumer.join().unwrap();
+
+    println!("threaded_sum: {}", *total.lock().unwrap());
+}
+
+async fn say(label: &str, value: i32) -> i32 {
+    println!("async {}: {}", label, value);
+    value
+}
+
+async fn run_tasks() -> i32 {
+    let a = say("first", 10).await;
+    let b = say("second", 20).await;
+    a + b
+}
+
+fn noop_raw_waker() -> RawWaker {
+    fn no_op(_: *const ()) {}
+    fn clone(_: *const ()) -> RawWaker {
+        noop_raw_waker()
+    }
+    let vtable = &RawWakerVTable::new(clone, no_op, no_op, no_op);
+    RawWaker::new(std::ptr::null(), vtable)
+}
+
+fn block_on<F: Future>(mut future: F) -> F::Output {
+    let waker = unsafe { Waker::from_raw(noop_raw_waker()) };
+    let mut cx = Context::from_waker(&waker);
+    let mut future = unsafe { Pin::new_unchecked(&mut future) };
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+pub fn async_sum() {
+    let sum = block_on(run_tasks());
+    println!("async_sum: {}", sum);
+}
@@ -0,0 +1,24 @@
+// Result/Option demos that contrast with the infallible style of `str_1`/`str_2`.
+
+use std::num::ParseIntError;
+
+pub fn parse_int(s: &str) -> Result<i32, ParseIntError> {
+    s.parse::<i32>()
+}
+
+pub fn find_first_digit(s: &str) -> Option<char> {
+    s.chars().find(|c| c.is_ascii_digit())
+}
+
+pub fn run(input: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let parsed = parse_int(input)?;
+    println!("parsed: {}", parsed);
+
+    if let Some(digit) = find_first_digit("abc123") {
+        println!("first digit: {}", digit);
+    } else {
+        println!("no digit found");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+// Generics and trait bounds, filling the gap left by the concrete examples
+// in `my_for_loop_2` and friends.
+
+pub fn largest<T: PartialOrd + Copy>(list: &[T]) -> T {
+    let mut largest = list[0];
+    for &item in list {
+        if item > largest {
+            largest = item;
+        }
+    }
+    largest
+}
+
+pub struct Pair<T> {
+    pub first: T,
+    pub second: T,
+}
+
+impl<T> Pair<T> {
+    pub fn new(first: T, second: T) -> Self {
+        Pair { first, second }
+    }
+}
+
+impl<T: PartialOrd + std::fmt::Display> Pair<T> {
+    pub fn cmp_display(&self) {
+        if self.first >= self.second {
+            println!("largest is first = {}", self.first);
+        } else {
+            println!("largest is second = {}", self.second);
+        }
+    }
+}
+
+pub trait Summary {
+    fn summarize(&self) -> String;
+}
+
+pub struct Article {
+    pub headline: String,
+}
+
+impl Summary for Article {
+    fn summarize(&self) -> String {
+        format!("Article: {}", self.headline)
+    }
+}
+
+pub struct Tweet {
+    pub username: String,
+}
+
+impl Summary for Tweet {
+    fn summarize(&self) -> String {
+        format!("Tweet from @{}", self.username)
+    }
+}
+
+pub fn notify(item: &impl Summary) {
+    println!("Breaking news! {}", item.summarize());
+}
+
+pub fn demo() {
+    println!("largest numbers: {}", largest(&[10, 20, 30]));
+    println!("largest strs: {}", largest(&["a", "c", "b"]));
+
+    let pair = Pair::new(5, 10);
+    pair.cmp_display();
+
+    notify(&Article {
+        headline: String::from("Rust generics explained"),
+    });
+    notify(&Tweet {
+        username: String::from("rustlang"),
+    });
+}
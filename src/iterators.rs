@@ -0,0 +1,48 @@
+// Iterator-adapter showcase, contrasting with the raw `for` loops in
+// `my_for_loop_1`/`my_for_loop_2`.
+
+pub fn sum_range_for() -> i32 {
+    let mut sum = 0;
+    for n in 1..=3 {
+        sum += n;
+    }
+    sum
+}
+
+pub fn sum_range_iter() -> i32 {
+    (1..=3).sum()
+}
+
+pub fn doubled_evens_for() -> Vec<i32> {
+    let arr = [10, 20, 30];
+    let mut result = Vec::new();
+    for i in arr {
+        if i % 2 == 0 {
+            result.push(i * 2);
+        }
+    }
+    result
+}
+
+pub fn doubled_evens_iter() -> Vec<i32> {
+    let arr = [10, 20, 30];
+    arr.iter().filter(|&&i| i % 2 == 0).map(|i| i * 2).collect()
+}
+
+pub fn demo() {
+    println!("sum_range_for: {}", sum_range_for());
+    println!("sum_range_iter: {}", sum_range_iter());
+
+    println!("doubled_evens_for: {:?}", doubled_evens_for());
+    println!("doubled_evens_iter: {:?}", doubled_evens_iter());
+
+    for (index, value) in (1..=3).enumerate() {
+        println!("enumerate: {} -> {}", index, value);
+    }
+
+    let letters = ["a", "b", "c"];
+    let numbers = [10, 20, 30];
+    for (letter, number) in letters.iter().zip(numbers.iter()) {
+        println!("zip: {} {}", letter, number);
+    }
+}
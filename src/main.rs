@@ -1,3 +1,9 @@
+mod borrowing;
+mod concurrency;
+mod error_handling;
+mod generics;
+mod iterators;
+
 fn main() {
     println!("Hello, world!");
     // println!("Hello, 林新德");
@@ -5,34 +11,98 @@ fn main() {
     my_for_loop_2();
     str_1();
     str_2();
+    borrowing::move_semantics();
+    borrowing::shared_references();
+    borrowing::mutable_reference();
+    borrowing::borrowed_slice();
+
+    if let Err(e) = error_handling::run("42") {
+        println!("error_handling failed: {}", e);
+    }
+    if let Err(e) = error_handling::run("not a number") {
+        println!("error_handling failed: {}", e);
+    }
+
+    concurrency::threaded_sum();
+    concurrency::async_sum();
+
+    generics::demo();
+
+    iterators::demo();
+}
+
+fn sum_range() -> i32 {
+    let mut sum = 0;
+    for n in 1..=3 {
+        sum += n;
+    }
+    sum
 }
 
 fn my_for_loop_1() {
     for n in 1..=3 {
         println!("loop_1: {}", n);
     }
+    println!("loop_1 sum: {}", sum_range());
 }
 
-fn my_for_loop_2() {
+fn collect_loop() -> Vec<i32> {
     let arr = [10, 20, 30];
+    let mut collected = Vec::new();
     for i in arr {
+        collected.push(i);
+    }
+    collected
+}
+
+fn my_for_loop_2() {
+    for i in collect_loop() {
         println!("loop_2: {}", i);
     }
 }
 
+fn str_1_final() -> &'static str {
+    "Hello, Rust"
+}
+
 fn str_1() {
-    let mut str1 = "Hello, String";
-    println!("{}", str1);
-    str1 = "Hello, Rust";
-    println!("{}", str1);
+    println!("Hello, String");
+    println!("{}", str_1_final());
 }
 
-fn str_2() {
-    let mut str1 = String::from("Hello, String2");
-    println!("{}", str1);
-    str1 = String::from("Hello, Rust2");
-    println!("{}", str1);
+fn str_2_final() -> String {
+    let mut str1 = String::from("Hello, Rust2");
     let str2 = &mut str1;
     // println!("{}", str1);
-    println!("{}", str2);
+    str2.clone()
+}
+
+fn str_2() {
+    println!("Hello, String2");
+    println!("{}", str_2_final());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sum_range() {
+        assert_eq!(sum_range(), 6);
+    }
+
+    #[test]
+    fn test_collect_loop() {
+        assert_eq!(collect_loop(), vec![10, 20, 30]);
+    }
+
+    #[test]
+    fn test_str_1_final() {
+        assert_eq!(str_1_final(), "Hello, Rust");
+    }
+
+    #[test]
+    fn test_str_2_final() {
+        assert_eq!(str_2_final(), "Hello, Rust2");
+    }
 }